@@ -0,0 +1,34 @@
+pub mod window;
+
+use serde::{Deserialize, Serialize};
+
+use crate::config::window::WindowConfig;
+
+fn default_opacity() -> f32 {
+    1.
+}
+
+/// Top level config type which holds everything necessary to run Alacritty.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct UiConfig {
+    /// Window settings.
+    #[serde(default)]
+    pub window: WindowConfig,
+
+    /// Background opacity from `0.0` (fully transparent) to `1.0` (fully opaque).
+    #[serde(default = "default_opacity")]
+    opacity: f32,
+}
+
+impl Default for UiConfig {
+    fn default() -> Self {
+        Self { window: WindowConfig::default(), opacity: default_opacity() }
+    }
+}
+
+impl UiConfig {
+    /// Opacity the window should have.
+    pub fn window_opacity(&self) -> f32 {
+        self.opacity.clamp(0., 1.)
+    }
+}
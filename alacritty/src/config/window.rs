@@ -0,0 +1,138 @@
+use serde::{Deserialize, Serialize};
+#[cfg(target_os = "macos")]
+use winit::platform::macos::OptionAsAlt;
+use winit::window::Theme;
+
+/// Window decoration.
+#[derive(Serialize, Deserialize, Debug, Default, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "kebab-case")]
+pub enum Decorations {
+    #[default]
+    Full,
+    Transparent,
+    Buttonless,
+    None,
+}
+
+/// Window startup mode.
+#[derive(Serialize, Deserialize, Debug, Default, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "kebab-case")]
+pub enum StartupMode {
+    #[default]
+    Windowed,
+    Maximized,
+    Fullscreen,
+    #[cfg(target_os = "macos")]
+    SimpleFullscreen,
+}
+
+/// Window position, in physical pixels.
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Position {
+    pub x: i32,
+    pub y: i32,
+}
+
+/// Selects a monitor to place a window on, either by its index in enumeration order or by the
+/// name reported by the windowing system.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+#[serde(untagged)]
+pub enum MonitorSelector {
+    Index(usize),
+    Name(String),
+}
+
+/// Window class applied via `_NET_WM_CLASS`/`WM_CLASS`.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+pub struct Class {
+    pub general: String,
+    pub instance: String,
+}
+
+impl Default for Class {
+    fn default() -> Self {
+        Self { general: "Alacritty".into(), instance: "Alacritty".into() }
+    }
+}
+
+/// Identity of a window, used for its title and windowing-system class.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Identity {
+    pub title: String,
+    pub class: Class,
+}
+
+/// Color space used by a macOS window when rendering its contents.
+///
+/// `Srgb` clamps truecolor values to the standard gamut, while `DisplayP3` and `Native` allow
+/// wider-gamut colors to show up correctly saturated on capable displays.
+#[cfg(target_os = "macos")]
+#[derive(Serialize, Deserialize, Debug, Default, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "kebab-case")]
+pub enum Colorspace {
+    /// Clamp to the standard (sRGB) gamut.
+    #[default]
+    Srgb,
+    /// Wide-gamut Display P3, as used by most modern Apple displays.
+    DisplayP3,
+    /// Whatever color space the monitor natively reports.
+    Native,
+}
+
+#[derive(Serialize, Deserialize, Debug, Default, Clone, PartialEq)]
+pub struct WindowConfig {
+    /// Initial window position.
+    #[serde(default)]
+    pub position: Option<Position>,
+
+    /// Monitor to place the window on at startup.
+    #[serde(default)]
+    pub monitor: Option<MonitorSelector>,
+
+    /// Window decorations.
+    #[serde(default)]
+    pub decorations: Decorations,
+
+    /// Override the window theme used to render decorations.
+    #[serde(skip)]
+    pub decorations_theme_variant: Option<Theme>,
+
+    /// Background blur.
+    #[serde(default)]
+    pub blur: bool,
+
+    /// X11 window ID to embed Alacritty within.
+    #[serde(skip)]
+    pub embed: Option<u32>,
+
+    /// Color space used to render the window's contents.
+    #[cfg(target_os = "macos")]
+    #[serde(default)]
+    pub colorspace: Colorspace,
+
+    /// How the `Option` key is interpreted.
+    #[cfg(target_os = "macos")]
+    #[serde(default)]
+    option_as_alt: OptionAsAlt,
+
+    /// Startup state of the window.
+    #[serde(default)]
+    startup_mode: StartupMode,
+}
+
+impl WindowConfig {
+    /// Whether the window should start maximized.
+    pub fn maximized(&self) -> bool {
+        self.startup_mode == StartupMode::Maximized
+    }
+
+    /// Whether the window should start in fullscreen.
+    pub fn fullscreen(&self) -> bool {
+        self.startup_mode == StartupMode::Fullscreen
+    }
+
+    #[cfg(target_os = "macos")]
+    pub fn option_as_alt(&self) -> OptionAsAlt {
+        self.option_as_alt
+    }
+}
@@ -20,16 +20,18 @@ use std::fmt::{self, Display, Formatter};
 
 #[cfg(target_os = "macos")]
 use {
-    cocoa::appkit::NSColorSpace,
+    cocoa::appkit::{NSColorSpace, NSWindowOrderingMode},
     cocoa::base::{id, nil, NO, YES},
-    objc::{msg_send, sel, sel_impl},
+    objc::{class, msg_send, sel, sel_impl},
     winit::platform::macos::{OptionAsAlt, WindowBuilderExtMacOS, WindowExtMacOS},
 };
 
 use raw_window_handle::{HasRawWindowHandle, RawWindowHandle};
+use serde::{Deserialize, Serialize};
 use winit::dpi::{PhysicalPosition, PhysicalSize};
 use winit::event_loop::EventLoopWindowTarget;
 use winit::monitor::MonitorHandle;
+use winit::raw_window_handle::HasWindowHandle;
 #[cfg(windows)]
 use winit::platform::windows::IconExtWindows;
 use winit::window::{
@@ -39,7 +41,9 @@ use winit::window::{
 
 use alacritty_terminal::index::Point;
 
-use crate::config::window::{Decorations, Identity, WindowConfig};
+#[cfg(target_os = "macos")]
+use crate::config::window::Colorspace;
+use crate::config::window::{Decorations, Identity, MonitorSelector, WindowConfig};
 use crate::config::UiConfig;
 use crate::display::SizeInfo;
 
@@ -94,6 +98,31 @@ impl From<crossfont::Error> for Error {
     }
 }
 
+/// State of a window's placement, independent of its concrete position/size.
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum WindowState {
+    Windowed,
+    Maximized,
+    Fullscreen,
+}
+
+/// Position, size, and state of a window, for inheriting its placement into a child window.
+///
+/// Also suited to saving/restoring a window's placement across restarts, but nothing in this
+/// series writes `WindowBounds` to, or reads it back from, the state dir -- that save/load path
+/// still needs to be wired up by whatever owns the window's lifecycle at startup/shutdown.
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq)]
+pub struct WindowBounds {
+    /// Window position in physical pixels.
+    pub position: PhysicalPosition<i32>,
+
+    /// Window inner size in physical pixels.
+    pub size: PhysicalSize<u32>,
+
+    /// Window state, like maximized or fullscreen.
+    pub state: WindowState,
+}
+
 /// A window which can be used for displaying the terminal.
 ///
 /// Wraps the underlying windowing library to provide a stable API in Alacritty.
@@ -112,6 +141,9 @@ pub struct Window {
     /// Current window title.
     title: String,
 
+    /// Id of the window this window is owned by, if any.
+    parent_id: Option<WindowId>,
+
     is_x11: bool,
     current_mouse_cursor: CursorIcon,
     mouse_visible: bool,
@@ -142,11 +174,30 @@ impl Window {
             tabbing_id,
         );
 
+        // Place the window on the monitor requested in the config, if any, before applying an
+        // explicit position on top of it; an explicit `config.window.position` always wins when
+        // both are set, silently overriding the monitor selection rather than, say, being
+        // interpreted as relative to it.
+        //
+        // `monitor.position()` is the origin of the full display bounds, not its work area --
+        // winit's `MonitorHandle` has no way to query the latter -- so a window placed this way
+        // can still land under a macOS menu bar or Windows taskbar reserved on that monitor.
+        if let Some(selector) = &config.window.monitor {
+            if let Some(monitor) = Self::resolve_monitor(event_loop.available_monitors(), selector)
+            {
+                window_builder = window_builder.with_position(monitor.position());
+            }
+        }
+
         if let Some(position) = config.window.position {
             window_builder = window_builder
                 .with_position(PhysicalPosition::<i32>::from((position.x, position.y)));
         }
 
+        window_builder = window_builder
+            .with_maximized(config.window.maximized())
+            .with_fullscreen(config.window.fullscreen().then_some(Fullscreen::Borderless(None)));
+
         #[cfg(not(any(target_os = "macos", windows)))]
         if let Some(token) = event_loop.read_token_from_env() {
             log::debug!("Activating window with token: {token:?}");
@@ -163,16 +214,99 @@ impl Window {
             window_builder = window_builder.with_embed_parent_window(parent_window_id);
         }
 
+        Self::build_window(window_builder, event_loop, config, identity, None)
+    }
+
+    /// Create a new window owned by `parent`.
+    ///
+    /// The child window is positioned relative to the parent's frame, and is intended for things
+    /// like detached search overlays, command palettes, or preview popups that should be real OS
+    /// windows rather than in-terminal overlays.
+    ///
+    /// Whether the windowing system closes/minimizes the child together with its parent is
+    /// best-effort, resting entirely on `with_parent_window`'s platform support: solid on
+    /// Windows and macOS, inconsistent across X11 window managers, and largely unsupported on
+    /// Wayland. `parent_id()` only records the relationship for callers to query -- on platforms
+    /// where the OS doesn't enforce it, the caller must still propagate close/minimize itself.
+    ///
+    /// Unlike [`Window::new`], this intentionally skips `config.window.monitor`/`position`, the
+    /// X11 startup-notification token, and X11 embedding: those only make sense for independently
+    /// placed top-level windows, while a child always derives its placement from `parent`.
+    pub fn new_child<E>(
+        event_loop: &EventLoopWindowTarget<E>,
+        config: &UiConfig,
+        identity: &Identity,
+        parent: &Window,
+        #[rustfmt::skip]
+        #[cfg(target_os = "macos")]
+        tabbing_id: &Option<String>,
+        #[rustfmt::skip]
+        #[cfg(all(feature = "x11", not(any(target_os = "macos", windows))))]
+        x11_visual: Option<X11VisualInfo>,
+    ) -> Result<Window> {
+        let identity = identity.clone();
+        let mut window_builder = Window::get_platform_window(
+            &identity,
+            &config.window,
+            #[cfg(all(feature = "x11", not(any(target_os = "macos", windows))))]
+            x11_visual,
+            #[cfg(target_os = "macos")]
+            tabbing_id,
+        );
+
+        // Inherit the parent's size and appear offset by a small delta from the parent's frame
+        // position, rather than at the OS's default position.
+        let parent_bounds = parent.bounds();
+        let child_bounds = WindowBounds {
+            position: PhysicalPosition::new(
+                parent_bounds.position.x + 32,
+                parent_bounds.position.y + 32,
+            ),
+            size: parent_bounds.size,
+            state: WindowState::Windowed,
+        };
+        window_builder = Self::apply_bounds(window_builder, child_bounds);
+
+        // `with_parent_window` wants winit's own `rwh_06` handle type, not the `rwh_05` one
+        // `raw_window_handle()` returns for our `RawWindowHandle::*` matches further down.
+        if let Ok(parent_handle) = parent.window.window_handle() {
+            // SAFETY: The parent handle is only used by the windowing system to establish
+            // OS-level window ownership during window creation; the child does not retain it
+            // afterwards.
+            window_builder =
+                unsafe { window_builder.with_parent_window(Some(parent_handle.as_raw())) };
+        }
+
+        Self::build_window(window_builder, event_loop, config, identity, Some(parent.id()))
+    }
+
+    /// Apply the builder settings shared by [`Window::new`] and [`Window::new_child`], then
+    /// build and fully initialize the resulting [`WinitWindow`].
+    fn build_window<E>(
+        window_builder: WindowBuilder,
+        event_loop: &EventLoopWindowTarget<E>,
+        config: &UiConfig,
+        identity: Identity,
+        parent_id: Option<WindowId>,
+    ) -> Result<Window> {
         let window = window_builder
             .with_title(&identity.title)
             .with_theme(config.window.decorations_theme_variant)
             .with_visible(false)
             .with_transparent(true)
             .with_blur(config.window.blur)
-            .with_maximized(config.window.maximized())
-            .with_fullscreen(config.window.fullscreen())
             .build(event_loop)?;
 
+        Ok(Self::from_winit_window(window, config, identity.title, parent_id))
+    }
+
+    /// Finish initializing a [`WinitWindow`] into a [`Window`].
+    fn from_winit_window(
+        window: WinitWindow,
+        config: &UiConfig,
+        title: String,
+        parent_id: Option<WindowId>,
+    ) -> Self {
         // Text cursor.
         let current_mouse_cursor = CursorIcon::Text;
         window.set_cursor_icon(current_mouse_cursor);
@@ -184,23 +318,26 @@ impl Window {
         // Set initial transparency hint.
         window.set_transparent(config.window_opacity() < 1.);
 
-        #[cfg(target_os = "macos")]
-        use_srgb_color_space(&window);
-
         let scale_factor = window.scale_factor();
         log::info!("Window scale factor: {}", scale_factor);
         let is_x11 = matches!(window.raw_window_handle(), RawWindowHandle::Xlib(_));
 
-        Ok(Self {
+        let window = Self {
             requested_redraw: false,
-            title: identity.title,
+            title,
+            parent_id,
             current_mouse_cursor,
             mouse_visible: true,
             has_frame: true,
             scale_factor,
             window,
             is_x11,
-        })
+        };
+
+        #[cfg(target_os = "macos")]
+        window.set_color_space(config.window.colorspace);
+
+        window
     }
 
     #[inline]
@@ -208,6 +345,79 @@ impl Window {
         self.window.raw_window_handle()
     }
 
+    /// Position of the window's client area, if the platform supports querying it.
+    #[inline]
+    pub fn inner_position(&self) -> Option<PhysicalPosition<i32>> {
+        self.window.inner_position().ok()
+    }
+
+    /// Position of the window's frame (including any titlebar/border), if the platform supports
+    /// querying it.
+    #[inline]
+    pub fn outer_position(&self) -> Option<PhysicalPosition<i32>> {
+        self.window.outer_position().ok()
+    }
+
+    /// Id of the window that owns this window, if it was created with [`Window::new_child`].
+    #[inline]
+    pub fn parent_id(&self) -> Option<WindowId> {
+        self.parent_id
+    }
+
+    /// Get the current position, size, and state of the window, for inheriting into a child
+    /// window, or for a future state-dir save/restore path to persist (see [`WindowBounds`]).
+    pub fn bounds(&self) -> WindowBounds {
+        let state = if self.window.fullscreen().is_some() {
+            WindowState::Fullscreen
+        } else if self.window.is_maximized() {
+            WindowState::Maximized
+        } else {
+            WindowState::Windowed
+        };
+
+        // Capture the frame (outer) position, since that's the coordinate space
+        // `restore_bounds`/`apply_bounds` place it back in; capturing the inner position here
+        // would shift the window by a titlebar/border's worth on every save/restore cycle.
+        WindowBounds {
+            position: self.outer_position().unwrap_or_default(),
+            size: self.inner_size(),
+            state,
+        }
+    }
+
+    /// Restore previously captured [`WindowBounds`] on an already built window.
+    ///
+    /// Lets bounds be applied after [`Window::new`] without a dedicated constructor parameter --
+    /// currently unused, since nothing in this series calls it yet, but available for a future
+    /// state-dir restore-on-startup path. [`Window::new_child`] instead bakes the inherited
+    /// bounds into the builder before the window exists, via the private `apply_bounds` helper.
+    pub fn restore_bounds(&self, bounds: WindowBounds) {
+        self.window.set_outer_position(bounds.position);
+        let _ = self.window.request_inner_size(bounds.size);
+
+        match bounds.state {
+            WindowState::Windowed => {},
+            WindowState::Maximized => self.window.set_maximized(true),
+            WindowState::Fullscreen => {
+                self.window.set_fullscreen(Some(Fullscreen::Borderless(None)))
+            },
+        }
+    }
+
+    /// Apply previously saved/inherited [`WindowBounds`] to a not yet built window.
+    fn apply_bounds(window_builder: WindowBuilder, bounds: WindowBounds) -> WindowBuilder {
+        let window_builder =
+            window_builder.with_position(bounds.position).with_inner_size(bounds.size);
+
+        match bounds.state {
+            WindowState::Windowed => window_builder,
+            WindowState::Maximized => window_builder.with_maximized(true),
+            WindowState::Fullscreen => {
+                window_builder.with_fullscreen(Some(Fullscreen::Borderless(None)))
+            },
+        }
+    }
+
     #[inline]
     pub fn request_inner_size(&self, size: PhysicalSize<u32>) {
         let _ = self.window.request_inner_size(size);
@@ -404,6 +614,35 @@ impl Window {
         self.window.current_monitor()
     }
 
+    /// Enumerate all monitors known to the windowing system.
+    pub fn available_monitors(&self) -> impl Iterator<Item = MonitorHandle> {
+        self.window.available_monitors()
+    }
+
+    /// Move the window to the monitor matching `selector`, if one is found.
+    ///
+    /// Like the monitor placement in [`Window::new`], this uses the full display bounds rather
+    /// than the work area -- which winit doesn't expose -- so the window can end up under a
+    /// menu bar or taskbar reserved on the target monitor.
+    pub fn move_to_monitor(&self, selector: &MonitorSelector) {
+        if let Some(monitor) = Self::resolve_monitor(self.available_monitors(), selector) {
+            self.window.set_outer_position(monitor.position());
+        }
+    }
+
+    /// Resolve a [`MonitorSelector`] against an enumeration of monitors.
+    fn resolve_monitor(
+        mut monitors: impl Iterator<Item = MonitorHandle>,
+        selector: &MonitorSelector,
+    ) -> Option<MonitorHandle> {
+        match selector {
+            MonitorSelector::Index(index) => monitors.nth(*index),
+            MonitorSelector::Name(name) => {
+                monitors.find(|monitor| monitor.name().as_deref() == Some(name.as_str()))
+            },
+        }
+    }
+
     #[cfg(target_os = "macos")]
     pub fn set_simple_fullscreen(&self, simple_fullscreen: bool) {
         self.window.set_simple_fullscreen(simple_fullscreen);
@@ -437,6 +676,34 @@ impl Window {
         );
     }
 
+    /// Set the color space used to render the window's contents.
+    ///
+    /// `Srgb` clamps truecolor values to the standard gamut, while `DisplayP3` and `Native`
+    /// allow wider-gamut colors to show up correctly saturated on capable displays.
+    #[cfg(target_os = "macos")]
+    pub fn set_color_space(&self, colorspace: Colorspace) {
+        let raw_window = match self.raw_window_handle() {
+            RawWindowHandle::AppKit(handle) => handle.ns_window as id,
+            _ => return,
+        };
+
+        unsafe {
+            let color_space: id = match colorspace {
+                Colorspace::Srgb => NSColorSpace::sRGBColorSpace(nil),
+                Colorspace::DisplayP3 => msg_send![class!(NSColorSpace), displayP3ColorSpace],
+                // The window's own `colorSpace` just reflects whatever was last set on it, so
+                // reading it back here would be a no-op; the monitor's native space lives on its
+                // `NSScreen` instead.
+                Colorspace::Native => {
+                    let screen: id = msg_send![raw_window, screen];
+                    msg_send![screen, colorSpace]
+                },
+            };
+
+            let _: () = msg_send![raw_window, setColorSpace: color_space];
+        }
+    }
+
     /// Disable macOS window shadows.
     ///
     /// This prevents rendering artifacts from showing up when the window is transparent.
@@ -477,20 +744,70 @@ impl Window {
         self.window.select_previous_tab();
     }
 
+    /// Move the tab at index `from` to index `to` within the window's tab group.
     #[cfg(target_os = "macos")]
-    pub fn tabbing_id(&self) -> String {
-        self.window.tabbing_identifier()
+    pub fn move_tab_to_index(&self, from: usize, to: usize) {
+        let raw_window = match self.raw_window_handle() {
+            RawWindowHandle::AppKit(handle) => handle.ns_window as id,
+            _ => return,
+        };
+
+        unsafe {
+            let tab_group: id = msg_send![raw_window, tabGroup];
+            if tab_group == nil {
+                return;
+            }
+
+            // `NSWindowTabGroup` only exposes a read-only `windows` property -- there's no
+            // `removeWindow:`/`insertWindow:atIndex:` to reorder it directly. Reordering goes
+            // through the same documented `-[NSWindow addTabbedWindow:ordered:]` used to add a
+            // new tab: calling it with a window that's already in the target's tab group moves
+            // it next to that window instead of adding a duplicate entry.
+            let windows: id = msg_send![tab_group, windows];
+            let count: usize = msg_send![windows, count];
+            if from >= count || to >= count || from == to {
+                return;
+            }
+
+            let moved_window: id = msg_send![windows, objectAtIndex: from];
+            let anchor_window: id = msg_send![windows, objectAtIndex: to];
+            let ordering = if to < from {
+                NSWindowOrderingMode::NSWindowBelow
+            } else {
+                NSWindowOrderingMode::NSWindowAbove
+            };
+            let _: () = msg_send![anchor_window, addTabbedWindow: moved_window ordered: ordering];
+        }
     }
-}
 
-#[cfg(target_os = "macos")]
-fn use_srgb_color_space(window: &WinitWindow) {
-    let raw_window = match window.raw_window_handle() {
-        RawWindowHandle::AppKit(handle) => handle.ns_window as id,
-        _ => return,
-    };
-
-    unsafe {
-        let _: () = msg_send![raw_window, setColorSpace: NSColorSpace::sRGBColorSpace(nil)];
+    /// Detach the current tab into its own new window.
+    #[cfg(target_os = "macos")]
+    pub fn move_tab_to_new_window(&self) {
+        let raw_window = match self.raw_window_handle() {
+            RawWindowHandle::AppKit(handle) => handle.ns_window as id,
+            _ => return,
+        };
+
+        unsafe {
+            let _: () = msg_send![raw_window, moveTabToNewWindow: nil];
+        }
+    }
+
+    /// Merge all open windows into a single tabbed window.
+    #[cfg(target_os = "macos")]
+    pub fn merge_all_windows(&self) {
+        let raw_window = match self.raw_window_handle() {
+            RawWindowHandle::AppKit(handle) => handle.ns_window as id,
+            _ => return,
+        };
+
+        unsafe {
+            let _: () = msg_send![raw_window, mergeAllWindows: nil];
+        }
+    }
+
+    #[cfg(target_os = "macos")]
+    pub fn tabbing_id(&self) -> String {
+        self.window.tabbing_identifier()
     }
 }